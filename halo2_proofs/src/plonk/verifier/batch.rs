@@ -0,0 +1,221 @@
+use ff::{Field, FromUniformBytes};
+use halo2curves::pairing::MultiMillerLoop;
+use rand_core::OsRng;
+use rayon::prelude::*;
+
+use super::VerifyingKey;
+use crate::{
+    plonk::verify_proof,
+    poly::{
+        kzg::{
+            commitment::ParamsVerifierKZG,
+            msm::DualMSM,
+            multiopen::gwc::VerifierGWC,
+            strategy::BatchVerifier as SingleProofStrategy,
+        },
+        VerificationStrategy,
+    },
+    transcript::{Blake2bRead, TranscriptReadBuffer},
+    zal::{H2cEngine, MsmAccel},
+};
+
+struct BatchItem<E: MultiMillerLoop> {
+    instances: Vec<Vec<Vec<E::Scalar>>>,
+    proof: Vec<u8>,
+}
+
+/// Verifies many independent GWC proofs with a single pairing check.
+///
+/// Unlike `poly::kzg::strategy::BatchVerifier`, which accumulates the MSM
+/// terms of one in-flight proof at a time, this collector owns the full
+/// list of `(instances, proof)` pairs up front. Each proof's transcript
+/// replay (and the resulting per-proof MSM) is independent of the others,
+/// so [`BatchVerifier::verify`] runs them with rayon before folding the
+/// results, randomized with an independent scalar per proof, into one
+/// shared [`DualMSM`].
+#[derive(Default)]
+pub struct BatchVerifier<E: MultiMillerLoop> {
+    items: Vec<BatchItem<E>>,
+}
+
+impl<E: MultiMillerLoop> BatchVerifier<E>
+where
+    E::Scalar: FromUniformBytes<64>,
+{
+    /// Constructs an empty batch.
+    pub fn new() -> Self {
+        Self { items: vec![] }
+    }
+
+    /// Queues a proof, together with the public instances it was
+    /// generated against, for batch verification.
+    pub fn add_proof(&mut self, instances: Vec<Vec<Vec<E::Scalar>>>, proof: Vec<u8>) {
+        self.items.push(BatchItem { instances, proof });
+    }
+
+    /// Verifies every queued proof.
+    ///
+    /// Returns `false` if *any* proof was invalid; a caller that needs to
+    /// identify which proof failed must re-verify the proofs separately,
+    /// since a single failing term collapses the whole accumulator.
+    pub fn verify<'params>(
+        self,
+        params: &'params ParamsVerifierKZG<E>,
+        vk: &VerifyingKey<E::G1Affine>,
+    ) -> bool {
+        let engine = H2cEngine::new();
+
+        // Each item is independent until its share of the accumulator is
+        // folded in below, so the transcript replay and MSM construction
+        // for every proof can run concurrently.
+        let strategies: Vec<Option<SingleProofStrategy<'params, E, OsRng>>> = self
+            .items
+            .into_par_iter()
+            .map(|item| {
+                let mut transcript = Blake2bRead::init(&item.proof[..]);
+                let strategy = SingleProofStrategy::new(params, OsRng);
+                // `verify_proof` wants `&[&[&[Scalar]]]`; build that nested
+                // slice-of-references view over our owned `Vec<Vec<Vec<_>>>`.
+                let instances: Vec<Vec<&[E::Scalar]>> = item
+                    .instances
+                    .iter()
+                    .map(|instance| instance.iter().map(Vec::as_slice).collect())
+                    .collect();
+                let instances: Vec<&[&[E::Scalar]]> =
+                    instances.iter().map(Vec::as_slice).collect();
+                verify_proof::<_, VerifierGWC<'params, E>, _, _, _>(
+                    params,
+                    vk,
+                    strategy,
+                    &instances,
+                    &mut transcript,
+                )
+                .ok()
+            })
+            .collect();
+
+        let mut acc = DualMSM::new(params);
+        for strategy in strategies {
+            match strategy {
+                // `SingleProofStrategy::process` scales the still-empty
+                // accumulator it starts from, so it contributes nothing: we
+                // must draw our own independent scalar here before folding
+                // each proof's terms in, or a malicious proof could be
+                // crafted to cancel another's contribution to `acc`.
+                Some(mut strategy) => {
+                    let r = E::Scalar::random(&mut OsRng);
+                    strategy.msm_accumulator.scale(r);
+                    acc.add_msm(&strategy.msm_accumulator);
+                }
+                None => return false,
+            }
+        }
+
+        acc.check(&engine)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ff::Field;
+    use halo2curves::bn256::{Bn256, Fr, G1Affine};
+    use rand_core::OsRng;
+
+    use super::BatchVerifier;
+    use crate::{
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        plonk::{
+            create_proof, keygen_pk, keygen_vk, Advice, Circuit, Column, ConstraintSystem, Error,
+            VerifyingKey,
+        },
+        poly::kzg::{commitment::ParamsKZG, multiopen::ProverGWC},
+        transcript::{Blake2bWrite, Challenge255, TranscriptWriterBuffer},
+    };
+
+    #[derive(Clone, Copy)]
+    struct TrivialCircuit;
+
+    impl Circuit<Fr> for TrivialCircuit {
+        type Config = Column<Advice>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            *self
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let advice = meta.advice_column();
+            meta.enable_equality(advice);
+            advice
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), Error> {
+            layouter.assign_region(
+                || "assign a single value",
+                |mut region| {
+                    region.assign_advice(|| "value", config, 0, || Value::known(Fr::ONE))
+                },
+            )?;
+            Ok(())
+        }
+    }
+
+    type Proofs = Vec<(Vec<Vec<Vec<Fr>>>, Vec<u8>)>;
+
+    // Generates `n` independently-valid proofs of `TrivialCircuit`, each with
+    // no public instances.
+    fn valid_proofs(n: usize) -> (ParamsKZG<Bn256>, VerifyingKey<G1Affine>, Proofs) {
+        let params = ParamsKZG::<Bn256>::setup(3, OsRng);
+        let vk = keygen_vk(&params, &TrivialCircuit).expect("keygen_vk should not fail");
+        let pk = keygen_pk(&params, vk.clone(), &TrivialCircuit).expect("keygen_pk should not fail");
+
+        let proofs = (0..n)
+            .map(|_| {
+                let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
+                create_proof::<_, ProverGWC<_>, _, _, _, _>(
+                    &params,
+                    &pk,
+                    &[TrivialCircuit],
+                    &[&[]],
+                    OsRng,
+                    &mut transcript,
+                )
+                .expect("proof generation should not fail");
+                (vec![vec![]], transcript.finalize())
+            })
+            .collect();
+
+        (params, vk, proofs)
+    }
+
+    #[test]
+    fn batch_of_valid_proofs_verifies() {
+        let (params, vk, proofs) = valid_proofs(3);
+
+        let mut verifier = BatchVerifier::<Bn256>::new();
+        for (instances, proof) in proofs {
+            verifier.add_proof(instances, proof);
+        }
+        assert!(verifier.verify(&params.into_verifier_params(), &vk));
+    }
+
+    #[test]
+    fn batch_containing_one_invalid_proof_fails() {
+        let (params, vk, mut proofs) = valid_proofs(3);
+
+        // Corrupt one proof so that it no longer verifies on its own; a
+        // single bad proof must make the whole batch fail.
+        let bad_proof = &mut proofs[1].1;
+        *bad_proof.last_mut().expect("proof is non-empty") ^= 0xff;
+
+        let mut verifier = BatchVerifier::<Bn256>::new();
+        for (instances, proof) in proofs {
+            verifier.add_proof(instances, proof);
+        }
+        assert!(!verifier.verify(&params.into_verifier_params(), &vk));
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt::Debug;
+
+use group::Group;
+
+use super::query::VerifierQuery;
+use super::Error;
+use crate::arithmetic::CurveAffine;
+use crate::transcript::{EncodedChallenge, TranscriptRead};
+use crate::zal::MsmAccel;
+
+/// Bundles together the types that make up a commitment scheme, so that
+/// prover- and verifier-side parameters, MSM accumulators and guards can
+/// all be referred to generically by the code in this crate.
+pub trait CommitmentScheme {
+    /// Scalar field of the commitment scheme's curve.
+    type Scalar: ff::Field;
+    /// Curve used by the commitment scheme.
+    type Curve: CurveAffine<ScalarExt = Self::Scalar>;
+
+    /// Parameters needed to generate a proof.
+    type ParamsProver: Clone + Debug;
+    /// Parameters needed to verify a proof.
+    type ParamsVerifier: Clone + Debug;
+}
+
+/// Common parameters shared by prover and verifier parameter sets.
+pub trait Params<C: CurveAffine> {
+    /// Returns log_2(n), the number of circuit rows.
+    fn k(&self) -> u32;
+    /// Returns n, the number of circuit rows.
+    fn n(&self) -> u64;
+}
+
+/// Parameters needed to verify a proof. A marker trait over [`Params`]
+/// so that verifier-only parameter sets (which may be much smaller than
+/// their prover-side counterpart) can still be used generically.
+pub trait ParamsVerifier<C: CurveAffine>: Params<C> {}
+
+/// A multi-scalar multiplication accumulator that can be built up
+/// incrementally across several commitment openings before being
+/// checked (or combined into a larger batch) all at once.
+pub trait MSM<C: CurveAffine>: Clone + Debug + Send + Sync {
+    /// Appends a term `scalar * point` to the accumulator.
+    fn append_term(&mut self, scalar: C::Scalar, point: C::Curve);
+
+    /// Merges the terms of `other` into `self`.
+    fn add_msm(&mut self, other: &Self)
+    where
+        Self: Sized;
+
+    /// Scales every term currently in the accumulator by `factor`.
+    fn scale(&mut self, factor: C::Scalar);
+
+    /// Evaluates the accumulator and checks whether the result is the
+    /// identity, using the provided [`MsmAccel`] engine to perform the
+    /// multiexponentiation.
+    fn check(&self, engine: &impl MsmAccel<C>) -> bool {
+        bool::from(self.eval(engine).is_identity())
+    }
+
+    /// Evaluates the accumulator to a single curve point, using the
+    /// provided [`MsmAccel`] engine to perform the multiexponentiation.
+    fn eval(&self, engine: &impl MsmAccel<C>) -> C::Curve;
+
+    /// Returns the bases accumulated so far.
+    fn bases(&self) -> Vec<C::Curve>;
+
+    /// Returns the scalars accumulated so far.
+    fn scalars(&self) -> Vec<C::Scalar>;
+}
+
+/// Verifies a proof for a particular commitment scheme `S`.
+pub trait Verifier<'params, S: CommitmentScheme> {
+    /// Guard type, which helps ensure the results of a verification
+    /// its proper use.
+    type Guard;
+
+    /// Accumulator type for MSMs constructed during verification.
+    type MSMAccumulator;
+
+    /// Creates a new verifier from the given verifier parameters.
+    fn new(params: &'params S::ParamsVerifier) -> Self;
+
+    /// Process the provided queries and accumulate the resulting MSM
+    /// terms into `msm_accumulator`, using `engine` to perform any
+    /// multiexponentiations required along the way.
+    fn verify_proof<
+        'com,
+        Ch: EncodedChallenge<S::Curve>,
+        T: TranscriptRead<S::Curve, Ch>,
+        I,
+    >(
+        &self,
+        engine: &impl MsmAccel<S::Curve>,
+        transcript: &mut T,
+        queries: I,
+        msm_accumulator: Self::MSMAccumulator,
+    ) -> Result<Self::Guard, Error>
+    where
+        I: IntoIterator<Item = VerifierQuery<'com, S::Curve>> + Clone;
+}
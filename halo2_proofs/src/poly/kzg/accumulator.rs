@@ -0,0 +1,129 @@
+use std::fmt;
+
+use ff::PrimeField;
+use halo2curves::CurveAffine;
+use num_bigint::BigUint;
+
+/// Width, in bits, of each limb produced by [`decompose_base_field_elem`].
+/// 88 bits is small enough that each limb comfortably fits inside the
+/// native scalar field used by typical outer circuits, while still
+/// keeping the limb count low.
+pub const LIMB_BITS: usize = 88;
+
+/// Number of limbs used to represent a base-field element. Base fields
+/// used by the curves in this crate are at most ~256 bits wide, so three
+/// 88-bit limbs (264 bits) are always sufficient.
+pub const NUM_LIMBS: usize = 3;
+
+/// The four base-field limb sets making up one [`DualMSM`](super::msm::DualMSM)
+/// accumulator: the `x` and `y` coordinates of its `left` and `right`
+/// points, each decomposed into [`NUM_LIMBS`] limbs of [`LIMB_BITS`] bits.
+/// An outer (aggregation) circuit can take these as public inputs and
+/// re-check `e(left, s_g2) = e(right, g2)` over several such accumulators
+/// at once.
+#[derive(Clone, Debug)]
+pub struct AccumulatorLimbs<F: PrimeField> {
+    /// Limbs of `left.x`, `left.y`, `right.x`, `right.y`, in that order.
+    pub limbs: Vec<F>,
+}
+
+impl<F: PrimeField> AccumulatorLimbs<F> {
+    /// Decomposes the two points of a (collapsed) KZG accumulator into
+    /// fixed-width limbs over the non-native base field `F`.
+    ///
+    /// Returns [`IdentityAccumulatorPoint`] if either point is the identity,
+    /// which has no affine coordinates to decompose; a collapsed `DualMSM`
+    /// can legitimately land on the identity (e.g. a canceling accumulator),
+    /// so callers must handle this rather than the caller's proof being the
+    /// only way to trigger it.
+    pub fn from_accumulator<C>(left: C, right: C) -> Result<Self, IdentityAccumulatorPoint>
+    where
+        C: CurveAffine<Base = F>,
+    {
+        let mut limbs = Vec::with_capacity(4 * NUM_LIMBS);
+        for point in [left, right] {
+            // Some curve implementations report the identity's `(0, 0)`
+            // encoding as valid coordinates, so reject it explicitly.
+            if bool::from(point.is_identity()) {
+                return Err(IdentityAccumulatorPoint);
+            }
+            let coords = Option::from(point.coordinates()).ok_or(IdentityAccumulatorPoint)?;
+            limbs.extend(decompose_base_field_elem(coords.x()));
+            limbs.extend(decompose_base_field_elem(coords.y()));
+        }
+        Ok(Self { limbs })
+    }
+}
+
+/// Returned by [`AccumulatorLimbs::from_accumulator`] when one of the two
+/// accumulator points is the identity and therefore has no affine
+/// coordinates to decompose into limbs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IdentityAccumulatorPoint;
+
+impl fmt::Display for IdentityAccumulatorPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "accumulator point is the identity and has no affine coordinates to decompose"
+        )
+    }
+}
+
+impl std::error::Error for IdentityAccumulatorPoint {}
+
+/// Decomposes a single base-field element into [`NUM_LIMBS`] limbs of
+/// [`LIMB_BITS`] bits each, least-significant limb first, re-encoded as
+/// elements of `F` (the element's own field) so they can be used directly
+/// as public inputs.
+///
+/// Requires `F::Repr` to be little-endian (true of every curve currently
+/// used in this crate): the element and each limb are read from, and
+/// written to, `F::Repr` bytes in little-endian order.
+pub fn decompose_base_field_elem<F: PrimeField>(elem: &F) -> [F; NUM_LIMBS] {
+    let bytes = elem.to_repr();
+    let value = BigUint::from_bytes_le(bytes.as_ref());
+    let mask = (BigUint::from(1u64) << LIMB_BITS) - 1u64;
+
+    let mut limbs = [F::ZERO; NUM_LIMBS];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let shifted = (&value >> (i * LIMB_BITS)) & &mask;
+        let shifted_bytes = shifted.to_bytes_le();
+        let mut repr = F::Repr::default();
+        repr.as_mut()[..shifted_bytes.len()].copy_from_slice(&shifted_bytes);
+        *limb = F::from_repr(repr).expect("limb fits in F");
+    }
+    limbs
+}
+
+#[cfg(test)]
+mod tests {
+    use ff::Field;
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use halo2curves::bn256::{Fq, G1Affine, G1};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn limbs_recompose_to_the_original_element() {
+        let elem = Fq::random(OsRng);
+        let limbs = decompose_base_field_elem(&elem);
+
+        let mut recomposed = BigUint::from(0u64);
+        for (i, limb) in limbs.iter().enumerate() {
+            recomposed += BigUint::from_bytes_le(limb.to_repr().as_ref()) << (i * LIMB_BITS);
+        }
+
+        assert_eq!(recomposed, BigUint::from_bytes_le(elem.to_repr().as_ref()));
+    }
+
+    #[test]
+    fn from_accumulator_rejects_an_identity_point() {
+        let identity = G1Affine::identity();
+        let point = G1::random(OsRng).to_affine();
+
+        assert!(AccumulatorLimbs::from_accumulator(identity, point).is_err());
+        assert!(AccumulatorLimbs::from_accumulator(point, point).is_ok());
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use halo2curves::pairing::Engine;
+
+use crate::poly::commitment::{CommitmentScheme, Params, ParamsVerifier as ParamsVerifierTrait};
+
+/// Full KZG parameters, including the entire O(2^k) SRS needed by the
+/// prover. The verifier only ever needs a handful of these elements; see
+/// [`ParamsVerifierKZG`] for the slimmed-down verifier-side parameters.
+#[derive(Clone, Debug)]
+pub struct ParamsKZG<E: Engine> {
+    pub(crate) k: u32,
+    pub(crate) n: u64,
+    /// SRS group elements `[g, [s]g, [s^2]g, ...]` in `E::G1`.
+    pub(crate) g: Vec<E::G1Affine>,
+    /// The second group generator, `g2`.
+    pub(crate) g2: E::G2Affine,
+    /// The toxic-waste-scaled generator, `[s]g2`.
+    pub(crate) s_g2: E::G2Affine,
+}
+
+impl<E: Engine + Debug> Params<E::G1Affine> for ParamsKZG<E> {
+    fn k(&self) -> u32 {
+        self.k
+    }
+
+    fn n(&self) -> u64 {
+        self.n
+    }
+}
+
+impl<E: Engine> ParamsKZG<E> {
+    /// Drops the O(2^k) SRS `g` vector, keeping only the handful of
+    /// elements the verifier actually uses (`g[0]`, `g2`, `s_g2`). This
+    /// turns the linear-size prover parameters into a small, constant-size
+    /// verifier parameter set, which is also much cheaper to serialize.
+    pub fn into_verifier_params(self) -> ParamsVerifierKZG<E> {
+        ParamsVerifierKZG {
+            k: self.k,
+            n: self.n,
+            g0: self.g[0],
+            g2: self.g2,
+            s_g2: self.s_g2,
+        }
+    }
+}
+
+/// Slimmed-down KZG parameters sufficient to verify a proof: just the
+/// first SRS element in `G1` and the two `G2` elements used by the
+/// pairing check in [`super::msm::DualMSM::check`]. See
+/// [`ParamsKZG::into_verifier_params`].
+#[derive(Clone, Debug)]
+pub struct ParamsVerifierKZG<E: Engine> {
+    pub(crate) k: u32,
+    pub(crate) n: u64,
+    /// `g[0]` from the full SRS, used for the `-g0 * eval_multi` term.
+    pub(crate) g0: E::G1Affine,
+    /// The second group generator, `g2`.
+    pub(crate) g2: E::G2Affine,
+    /// The toxic-waste-scaled generator, `[s]g2`.
+    pub(crate) s_g2: E::G2Affine,
+}
+
+impl<E: Engine + Debug> Params<E::G1Affine> for ParamsVerifierKZG<E> {
+    fn k(&self) -> u32 {
+        self.k
+    }
+
+    fn n(&self) -> u64 {
+        self.n
+    }
+}
+
+impl<E: Engine + Debug> ParamsVerifierTrait<E::G1Affine> for ParamsVerifierKZG<E> {}
+
+/// Marker type binding together the curve, parameter and accumulator
+/// types used by the KZG polynomial commitment scheme.
+#[derive(Debug)]
+pub struct KZGCommitmentScheme<E: Engine> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: Engine + Debug> CommitmentScheme for KZGCommitmentScheme<E> {
+    type Scalar = E::Scalar;
+    type Curve = E::G1Affine;
+
+    type ParamsProver = ParamsKZG<E>;
+    type ParamsVerifier = ParamsVerifierKZG<E>;
+}
@@ -0,0 +1,138 @@
+use std::fmt::Debug;
+
+use group::prime::PrimeCurveAffine;
+use group::Curve;
+use halo2curves::pairing::{Engine, MillerLoopResult, MultiMillerLoop};
+
+use super::commitment::ParamsVerifierKZG;
+use crate::{arithmetic::parallelize, poly::commitment::MSM, zal::MsmAccel};
+
+/// A multiscalar multiplication in the pairing group `E::G1`, accumulated
+/// as parallel vectors of scalars and bases rather than being evaluated
+/// eagerly. Appending a term is therefore O(1), and merging another
+/// `MSMKZG` is linear in the number of terms it holds; the actual
+/// multiexponentiation is deferred until [`MSMKZG::eval`]
+/// (or [`MSM::check`]) is called.
+#[derive(Clone, Debug)]
+pub struct MSMKZG<E: Engine> {
+    pub(crate) scalars: Vec<E::Scalar>,
+    pub(crate) bases: Vec<E::G1>,
+}
+
+impl<E: Engine> MSMKZG<E> {
+    /// Creates an empty MSM accumulator.
+    pub fn new() -> Self {
+        Self {
+            scalars: vec![],
+            bases: vec![],
+        }
+    }
+}
+
+impl<E: Engine> Default for MSMKZG<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Engine + Debug> MSM<E::G1Affine> for MSMKZG<E> {
+    fn append_term(&mut self, scalar: E::Scalar, point: E::G1) {
+        self.scalars.push(scalar);
+        self.bases.push(point);
+    }
+
+    fn add_msm(&mut self, other: &Self) {
+        self.scalars.extend(other.scalars.iter());
+        self.bases.extend(other.bases.iter());
+    }
+
+    fn scale(&mut self, factor: E::Scalar) {
+        if !self.scalars.is_empty() {
+            parallelize(&mut self.scalars, |scalars, _| {
+                for other_scalar in scalars {
+                    *other_scalar *= &factor;
+                }
+            })
+        }
+    }
+
+    fn eval(&self, engine: &impl MsmAccel<E::G1Affine>) -> E::G1 {
+        let mut bases = vec![E::G1Affine::identity(); self.scalars.len()];
+        E::G1::batch_normalize(&self.bases, &mut bases);
+        engine.msm(&self.scalars, &bases)
+    }
+
+    fn bases(&self) -> Vec<E::G1> {
+        self.bases.clone()
+    }
+
+    fn scalars(&self) -> Vec<E::Scalar> {
+        self.scalars.clone()
+    }
+}
+
+/// Two [`MSMKZG`] accumulators, `left` and `right`, that together defer a
+/// single pairing check `e(left, s_g2) =? e(right, g2)`. This is the shape
+/// produced by KZG multi-open verification: rather than performing the
+/// pairing immediately, the two multiexponentiations are accumulated (and
+/// potentially combined with other proofs' accumulators) before the one
+/// expensive `MillerLoop`/`final_exponentiation` is run.
+#[derive(Clone, Debug)]
+pub struct DualMSM<'params, E: Engine> {
+    pub(crate) params: &'params ParamsVerifierKZG<E>,
+    pub(crate) left: MSMKZG<E>,
+    pub(crate) right: MSMKZG<E>,
+}
+
+impl<'params, E: MultiMillerLoop + Debug> DualMSM<'params, E> {
+    /// Creates an empty dual MSM accumulator tied to `params`.
+    pub fn new(params: &'params ParamsVerifierKZG<E>) -> Self {
+        Self {
+            params,
+            left: MSMKZG::new(),
+            right: MSMKZG::new(),
+        }
+    }
+
+    /// Scales both the `left` and `right` accumulators by `e`.
+    pub fn scale(&mut self, e: E::Scalar) {
+        self.left.scale(e);
+        self.right.scale(e);
+    }
+
+    /// Merges another dual MSM into this one.
+    pub fn add_msm(&mut self, other: &Self) {
+        self.left.add_msm(&other.left);
+        self.right.add_msm(&other.right);
+    }
+
+    /// Evaluates `left` and `right` (using `engine` for the
+    /// multiexponentiations) and checks the pairing
+    /// `e(left, s_g2) =? e(right, g2)`.
+    pub fn check(self, engine: &impl MsmAccel<E::G1Affine>) -> bool {
+        let s_g2_prepared = E::G2Prepared::from(self.params.s_g2);
+        let n_g2_prepared = E::G2Prepared::from(-self.params.g2);
+
+        let left = self.left.eval(engine);
+        let right = self.right.eval(engine);
+
+        let term_1 = (&left.into(), &s_g2_prepared);
+        let term_2 = (&right.into(), &n_g2_prepared);
+
+        bool::from(
+            E::multi_miller_loop(&[term_1, term_2])
+                .final_exponentiation()
+                .is_identity(),
+        )
+    }
+
+    /// Collapses the accumulator to its two `G1` points without performing
+    /// the pairing, so that the check `e(left, s_g2) =? e(right, g2)` can
+    /// be deferred to (and batched with other accumulators by) an outer
+    /// circuit performing KZG accumulation. See
+    /// [`super::accumulator::AccumulatorLimbs`] for turning the result
+    /// into public-input limbs for such a circuit.
+    pub fn evaluate(self, engine: &impl MsmAccel<E::G1Affine>) -> (E::G1Affine, E::G1Affine) {
+        (self.left.eval(engine).to_affine(), self.right.eval(engine).to_affine())
+    }
+}
@@ -7,7 +7,7 @@ use crate::arithmetic::{eval_polynomial, lagrange_interpolate, CurveAffine, Fiel
 
 use crate::poly::commitment::Verifier;
 use crate::poly::commitment::MSM;
-use crate::poly::kzg::commitment::{KZGCommitmentScheme, ParamsKZG};
+use crate::poly::kzg::commitment::{KZGCommitmentScheme, ParamsVerifierKZG};
 use crate::poly::kzg::msm::{DualMSM, MSMKZG};
 use crate::poly::kzg::strategy::{BatchVerifier, GuardKZG};
 use crate::poly::query::Query;
@@ -18,6 +18,7 @@ use crate::poly::{
     Error,
 };
 use crate::transcript::{EncodedChallenge, TranscriptRead};
+use crate::zal::MsmAccel;
 
 use ff::Field;
 use group::Group;
@@ -27,7 +28,7 @@ use rand_core::RngCore;
 #[derive(Debug)]
 /// Concrete KZG verifier with GWC variant
 pub struct VerifierGWC<'params, E: Engine> {
-    params: &'params ParamsKZG<E>,
+    params: &'params ParamsVerifierKZG<E>,
 }
 
 impl<'params, E: MultiMillerLoop + Debug> Verifier<'params, KZGCommitmentScheme<E>>
@@ -36,7 +37,7 @@ impl<'params, E: MultiMillerLoop + Debug> Verifier<'params, KZGCommitmentScheme<
     type Guard = GuardKZG<'params, E>;
     type MSMAccumulator = DualMSM<'params, E>;
 
-    fn new(params: &'params ParamsKZG<E>) -> Self {
+    fn new(params: &'params ParamsVerifierKZG<E>) -> Self {
         Self { params }
     }
 
@@ -47,6 +48,7 @@ impl<'params, E: MultiMillerLoop + Debug> Verifier<'params, KZGCommitmentScheme<
         I,
     >(
         &self,
+        _engine: &impl MsmAccel<E::G1Affine>,
         transcript: &mut T,
         queries: I,
         mut msm_accumulator: DualMSM<'params, E>,
@@ -70,48 +72,78 @@ impl<'params, E: MultiMillerLoop + Debug> Verifier<'params, KZGCommitmentScheme<
         let mut witness = MSMKZG::<E>::new();
         let mut witness_with_aux = MSMKZG::<E>::new();
 
-        for (commitment_at_a_point, wi) in commitment_data.iter().zip(w.into_iter()) {
+        // `commitment_data[i]` previously had `witness`/`witness_with_aux`/
+        // `commitment_multi` rescaled by `u` on every subsequent iteration,
+        // i.e. it ends up weighted by `u^(n-1-i)` where `n` is the number
+        // of points. Computing that power up front and multiplying each
+        // term by it once at append time is equivalent (Horner's rule
+        // evaluated via powers instead of repeated rescaling), but turns
+        // assembly from O(n^2) into O(n).
+        let u_powers = {
+            let mut powers = vec![E::Scalar::one(); commitment_data.len()];
+            let mut acc = E::Scalar::one();
+            for power in powers.iter_mut().rev() {
+                *power = acc;
+                acc *= *u;
+            }
+            powers
+        };
+
+        for ((commitment_at_a_point, wi), u_i) in commitment_data
+            .iter()
+            .zip(w.into_iter())
+            .zip(u_powers.into_iter())
+        {
             assert!(!commitment_at_a_point.queries.is_empty());
             let z = commitment_at_a_point.point;
 
-            witness_with_aux.scale(*u);
-            witness_with_aux.append_term(z, wi.into());
-            witness.scale(*u);
-            witness.append_term(E::Scalar::one(), wi.into());
-            commitment_multi.scale(*u);
-            eval_multi = eval_multi * *u;
+            witness_with_aux.append_term(z * u_i, wi.into());
+            witness.append_term(u_i, wi.into());
+
+            // Same trick, one level down: the `v`-weight of the `j`-th
+            // query at this point is `v^(m-1-j)` where `m` is the number
+            // of queries sharing this point.
+            let v_powers = {
+                let mut powers = vec![E::Scalar::one(); commitment_at_a_point.queries.len()];
+                let mut acc = E::Scalar::one();
+                for power in powers.iter_mut().rev() {
+                    *power = acc;
+                    acc *= *v;
+                }
+                powers
+            };
 
-            let mut commitment_batch = MSMKZG::<E>::new();
             let mut eval_batch = E::Scalar::zero();
 
-            for query in commitment_at_a_point.queries.iter() {
+            for (query, v_j) in commitment_at_a_point.queries.iter().zip(v_powers.into_iter()) {
                 assert_eq!(query.get_point(), z);
 
                 let commitment = query.get_commitment();
                 let eval = query.get_eval();
+                let coeff = u_i * v_j;
 
-                commitment_batch.scale(*v);
                 match commitment {
                     CommitmentReference::Commitment(c) => {
-                        commitment_batch.append_term(E::Scalar::one(), (*c).into());
+                        commitment_multi.append_term(coeff, (*c).into());
                     }
                     CommitmentReference::MSM(msm) => {
-                        commitment_batch.add_msm(msm);
+                        for (scalar, base) in msm.scalars().into_iter().zip(msm.bases()) {
+                            commitment_multi.append_term(scalar * coeff, base);
+                        }
                     }
                 }
 
                 eval_batch = eval_batch * *v + eval;
             }
 
-            commitment_multi.add_msm(&commitment_batch);
-            eval_multi += eval_batch;
+            eval_multi += u_i * eval_batch;
         }
 
         msm_accumulator.left.add_msm(&witness);
 
         msm_accumulator.right.add_msm(&witness_with_aux);
         msm_accumulator.right.add_msm(&commitment_multi);
-        let g0: E::G1 = self.params.g[0].into();
+        let g0: E::G1 = self.params.g0.into();
         msm_accumulator.right.append_term(eval_multi, -g0);
 
         Ok(Self::Guard::new(msm_accumulator))
@@ -124,12 +156,13 @@ impl<'params, E: MultiMillerLoop + Debug, R: RngCore>
 {
     type Output = Self;
 
-    fn new(params: &'params ParamsKZG<E>, rng: R) -> Self {
+    fn new(params: &'params ParamsVerifierKZG<E>, rng: R) -> Self {
         BatchVerifier::new(params, rng)
     }
 
     fn process(
         mut self,
+        _engine: &impl MsmAccel<E::G1Affine>,
         f: impl FnOnce(DualMSM<'params, E>) -> Result<GuardKZG<'params, E>, crate::plonk::Error>,
     ) -> Result<Self::Output, crate::plonk::Error> {
         self.msm_accumulator.scale(E::Scalar::random(&mut self.rng));
@@ -139,7 +172,167 @@ impl<'params, E: MultiMillerLoop + Debug, R: RngCore>
         Ok(BatchVerifier::with(guard.msm_accumulator, self.rng))
     }
 
-    fn finalize(self) -> bool {
-        self.msm_accumulator.check()
+    fn finalize(self, engine: &impl MsmAccel<E::G1Affine>) -> bool {
+        self.msm_accumulator.check(engine)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::Curve;
+    use halo2curves::bn256::{Bn256, Fr, G1Affine, G1};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn msm_eval(msm: &MSMKZG<Bn256>) -> G1 {
+        msm.scalars()
+            .into_iter()
+            .zip(msm.bases())
+            .fold(G1::identity(), |acc, (scalar, base)| acc + base * scalar)
+    }
+
+    #[test]
+    fn aggregation_matches_naive_quadratic_reweighting() {
+        let points: Vec<Fr> = (0..3).map(|_| Fr::random(OsRng)).collect();
+        let commitments: Vec<G1Affine> = (0..6).map(|_| G1::random(OsRng).to_affine()).collect();
+
+        // At least one query is backed by an `MSM` rather than a single
+        // commitment, exercising the `CommitmentReference::MSM` branch that
+        // now re-expands `msm.scalars()`/`msm.bases()` term by term with a
+        // single combined coefficient instead of merging the whole MSM and
+        // rescaling it afterwards.
+        let msm_query = {
+            let mut msm = MSMKZG::<Bn256>::new();
+            msm.append_term(Fr::random(OsRng), G1::random(OsRng));
+            msm.append_term(Fr::random(OsRng), G1::random(OsRng));
+            msm
+        };
+
+        let queries = vec![
+            VerifierQuery::new_commitment(&commitments[0], points[0], Fr::random(OsRng)),
+            VerifierQuery::new_commitment(&commitments[1], points[0], Fr::random(OsRng)),
+            VerifierQuery::new_msm(&msm_query, points[0], Fr::random(OsRng)),
+            VerifierQuery::new_commitment(&commitments[2], points[1], Fr::random(OsRng)),
+            VerifierQuery::new_commitment(&commitments[3], points[1], Fr::random(OsRng)),
+            VerifierQuery::new_commitment(&commitments[4], points[1], Fr::random(OsRng)),
+            VerifierQuery::new_commitment(&commitments[5], points[2], Fr::random(OsRng)),
+        ];
+
+        let commitment_data = construct_intermediate_sets(queries);
+        let w: Vec<G1Affine> = (0..commitment_data.len())
+            .map(|_| G1::random(OsRng).to_affine())
+            .collect();
+        let u = Fr::random(OsRng);
+        let v = Fr::random(OsRng);
+
+        // The production (O(n)) aggregation, mirroring the logic of
+        // `verify_proof` above.
+        let (witness_new, witness_with_aux_new, commitment_multi_new, eval_multi_new) = {
+            let u_powers = {
+                let mut powers = vec![Fr::one(); commitment_data.len()];
+                let mut acc = Fr::one();
+                for power in powers.iter_mut().rev() {
+                    *power = acc;
+                    acc *= u;
+                }
+                powers
+            };
+
+            let mut commitment_multi = MSMKZG::<Bn256>::new();
+            let mut eval_multi = Fr::zero();
+            let mut witness = MSMKZG::<Bn256>::new();
+            let mut witness_with_aux = MSMKZG::<Bn256>::new();
+
+            for ((commitment_at_a_point, wi), u_i) in commitment_data
+                .iter()
+                .zip(w.iter().copied())
+                .zip(u_powers.into_iter())
+            {
+                let z = commitment_at_a_point.point;
+                witness_with_aux.append_term(z * u_i, wi.into());
+                witness.append_term(u_i, wi.into());
+
+                let v_powers = {
+                    let mut powers = vec![Fr::one(); commitment_at_a_point.queries.len()];
+                    let mut acc = Fr::one();
+                    for power in powers.iter_mut().rev() {
+                        *power = acc;
+                        acc *= v;
+                    }
+                    powers
+                };
+
+                let mut eval_batch = Fr::zero();
+                for (query, v_j) in commitment_at_a_point.queries.iter().zip(v_powers.into_iter()) {
+                    let coeff = u_i * v_j;
+                    match query.get_commitment() {
+                        CommitmentReference::Commitment(c) => {
+                            commitment_multi.append_term(coeff, (*c).into());
+                        }
+                        CommitmentReference::MSM(msm) => {
+                            for (scalar, base) in msm.scalars().into_iter().zip(msm.bases()) {
+                                commitment_multi.append_term(scalar * coeff, base);
+                            }
+                        }
+                    }
+                    eval_batch = eval_batch * v + query.get_eval();
+                }
+                eval_multi += u_i * eval_batch;
+            }
+
+            (witness, witness_with_aux, commitment_multi, eval_multi)
+        };
+
+        // The pre-refactor (O(n^2)) aggregation.
+        let (witness_naive, witness_with_aux_naive, commitment_multi_naive, eval_multi_naive) = {
+            let mut commitment_multi = MSMKZG::<Bn256>::new();
+            let mut eval_multi = Fr::zero();
+            let mut witness = MSMKZG::<Bn256>::new();
+            let mut witness_with_aux = MSMKZG::<Bn256>::new();
+
+            for (commitment_at_a_point, wi) in commitment_data.iter().zip(w.iter().copied()) {
+                let z = commitment_at_a_point.point;
+                witness_with_aux.scale(u);
+                witness_with_aux.append_term(z, wi.into());
+                witness.scale(u);
+                witness.append_term(Fr::one(), wi.into());
+                commitment_multi.scale(u);
+                eval_multi *= u;
+
+                let mut commitment_batch = MSMKZG::<Bn256>::new();
+                let mut eval_batch = Fr::zero();
+                for query in commitment_at_a_point.queries.iter() {
+                    commitment_batch.scale(v);
+                    eval_batch *= v;
+                    match query.get_commitment() {
+                        CommitmentReference::Commitment(c) => {
+                            commitment_batch.append_term(Fr::one(), (*c).into());
+                        }
+                        CommitmentReference::MSM(msm) => {
+                            for (scalar, base) in msm.scalars().into_iter().zip(msm.bases()) {
+                                commitment_batch.append_term(scalar, base);
+                            }
+                        }
+                    }
+                    eval_batch += query.get_eval();
+                }
+                commitment_multi.add_msm(&commitment_batch);
+                eval_multi += eval_batch;
+            }
+
+            (witness, witness_with_aux, commitment_multi, eval_multi)
+        };
+
+        assert_eq!(eval_multi_new, eval_multi_naive);
+        assert_eq!(msm_eval(&witness_new), msm_eval(&witness_naive));
+        assert_eq!(
+            msm_eval(&witness_with_aux_new),
+            msm_eval(&witness_with_aux_naive)
+        );
+        assert_eq!(
+            msm_eval(&commitment_multi_new),
+            msm_eval(&commitment_multi_naive)
+        );
     }
 }
\ No newline at end of file
@@ -0,0 +1,49 @@
+use std::fmt::Debug;
+
+use halo2curves::pairing::{Engine, MultiMillerLoop};
+use rand_core::RngCore;
+
+use super::commitment::ParamsVerifierKZG;
+use super::msm::DualMSM;
+
+/// Guard returned by [`super::multiopen::gwc::verifier::VerifierGWC`],
+/// wrapping the accumulated [`DualMSM`] whose pairing check has not yet
+/// been performed.
+#[derive(Debug)]
+pub struct GuardKZG<'params, E: Engine + Debug> {
+    pub(crate) msm_accumulator: DualMSM<'params, E>,
+}
+
+impl<'params, E: MultiMillerLoop + Debug> GuardKZG<'params, E> {
+    pub(crate) fn new(msm_accumulator: DualMSM<'params, E>) -> Self {
+        Self { msm_accumulator }
+    }
+}
+
+/// A [`VerificationStrategy`] that accumulates the MSM terms of any
+/// number of proofs, rescaling the terms accumulated so far by a fresh
+/// random scalar before merging in each new proof, and performs a single
+/// pairing check at the end.
+#[derive(Debug)]
+pub struct BatchVerifier<'params, E: Engine, R: RngCore> {
+    pub(crate) msm_accumulator: DualMSM<'params, E>,
+    pub(crate) rng: R,
+}
+
+impl<'params, E: MultiMillerLoop + Debug, R: RngCore> BatchVerifier<'params, E, R> {
+    /// Constructs an empty batch verifier over `params`.
+    pub fn new(params: &'params ParamsVerifierKZG<E>, rng: R) -> Self {
+        BatchVerifier {
+            msm_accumulator: DualMSM::new(params),
+            rng,
+        }
+    }
+
+    /// Constructs a batch verifier resuming from an existing accumulator.
+    pub fn with(msm_accumulator: DualMSM<'params, E>, rng: R) -> Self {
+        BatchVerifier {
+            msm_accumulator,
+            rng,
+        }
+    }
+}
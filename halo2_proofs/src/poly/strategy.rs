@@ -0,0 +1,32 @@
+use super::commitment::{CommitmentScheme, Verifier};
+use crate::plonk::Error;
+use crate::zal::MsmAccel;
+use rand_core::RngCore;
+
+/// Verification strategy, accumulating MSM terms across one or more
+/// proofs and deciding, via [`VerificationStrategy::finalize`], whether
+/// the accumulated checks hold.
+pub trait VerificationStrategy<'params, S: CommitmentScheme, V: Verifier<'params, S>, R: RngCore>
+where
+    Self: Sized,
+{
+    /// The type returned by `process`, typically `Self` so that
+    /// verification of several proofs can be chained.
+    type Output;
+
+    /// Creates a new verification strategy from the verifier params.
+    fn new(params: &'params S::ParamsVerifier, rng: R) -> Self;
+
+    /// Feeds the guard produced by a single proof's verification into
+    /// this strategy's running accumulator, using `engine` to perform
+    /// any multiexponentiations required along the way.
+    fn process(
+        self,
+        engine: &impl MsmAccel<S::Curve>,
+        f: impl FnOnce(V::MSMAccumulator) -> Result<V::Guard, Error>,
+    ) -> Result<Self::Output, Error>;
+
+    /// Finalizes the strategy, checking all of the accumulated MSM
+    /// terms at once using `engine` to perform the multiexponentiation.
+    fn finalize(self, engine: &impl MsmAccel<S::Curve>) -> bool;
+}
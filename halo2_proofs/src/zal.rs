@@ -0,0 +1,39 @@
+//! This module provides a "ZK Accel Layer" (ZAL) that abstracts away
+//! hardware-specific acceleration of the multi-scalar multiplications
+//! (MSMs) used throughout proof verification (and, in the future,
+//! proving). A `MsmAccel` engine is created once by the caller and
+//! threaded down into the verifier so that the heaviest scalar-mul work
+//! can be delegated to a swappable backend (CPU, GPU, ICICLE-style,
+//! etc.) without touching the verification logic itself.
+
+use crate::arithmetic::{best_multiexp, CurveAffine};
+
+/// Engine performing multi-scalar multiplications over `C`.
+///
+/// Implementors may dispatch to any backend (plain CPU code, a GPU
+/// kernel, a third-party MSM accelerator, ...) as long as the result
+/// matches the mathematical definition `sum_i coeffs[i] * bases[i]`.
+pub trait MsmAccel<C: CurveAffine>: Send + Sync {
+    /// Performs a multi-scalar multiplication, returning
+    /// `sum_i coeffs[i] * bases[i]`.
+    fn msm(&self, coeffs: &[C::Scalar], bases: &[C]) -> C::Curve;
+}
+
+/// Default, CPU-only [`MsmAccel`] engine. This is the engine used
+/// throughout the codebase unless a caller explicitly plugs in a
+/// hardware-accelerated alternative.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct H2cEngine;
+
+impl H2cEngine {
+    /// Creates a new CPU engine.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<C: CurveAffine> MsmAccel<C> for H2cEngine {
+    fn msm(&self, coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+        best_multiexp(coeffs, bases)
+    }
+}